@@ -10,14 +10,14 @@ fn main() {
 
     log::info!("Filewatcher Backload Commencing...");
     if let Err(e) = fw.backload() {
-        println!("Error initiating filewatcher {}, exiting...", e.to_string());
+        println!("Error initiating filewatcher {e}, exiting...");
         std::process::exit(1);
     }
     log::info!("Filewatcher Backload Complete!");
 
     log::info!("Filewatcher Beginning watch at {}", fw);
     if let Err(e) = fw.run() {
-        println!("Error initiating filewatcher {}, exiting...", e.to_string());
+        println!("Error initiating filewatcher {e}, exiting...");
         std::process::exit(1);
     }
 }
@@ -0,0 +1,5 @@
+/// Initializes the `log` backend from the `RUST_LOG` environment variable,
+/// defaulting to `info` when it's unset.
+pub fn init_logger() {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+}
@@ -1,15 +1,106 @@
 use anyhow::{anyhow, Result};
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use notify::event::{CreateKind, EventKind};
 use notify::{Config, Event, RecommendedWatcher, RecursiveMode, Watcher};
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::fmt::Display;
-use std::fs::{create_dir, read_dir, rename};
+use std::fs::{copy, create_dir, read_dir, remove_file, rename, File, OpenOptions};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::mpsc::RecvTimeoutError;
+use std::time::{Duration, Instant};
+use walkdir::WalkDir;
+
+mod ignore;
+mod rules;
+use ignore::IgnoreSet;
+use rules::RuleSet;
+
+/// Linux/BSD errno for a cross-device link, returned by `rename` when the
+/// source and destination live on different filesystems/mounts.
+const EXDEV: i32 = 18;
+
+/// Whether `path` is a `copy_then_remove` staging file (`.<name>.sorter-tmp`),
+/// which should never be tracked as an incoming file in its own right.
+fn is_sorter_tmp_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|n| n.starts_with('.') && n.ends_with(".sorter-tmp"))
+}
+
+/// Builds `<stem> (<n>).<ext>` next to `path`, e.g. `report (1).pdf`.
+fn numbered_path(path: &Path, n: u32) -> PathBuf {
+    let dir = path.parent().unwrap_or_else(|| Path::new(""));
+    let stem = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("file");
+    match path.extension().and_then(|e| e.to_str()) {
+        Some(ext) => dir.join(format!("{stem} ({n}).{ext}")),
+        None => dir.join(format!("{stem} ({n})")),
+    }
+}
 
 #[derive(Parser)]
 pub struct FileWatcher {
     target_dir: String,
+
+    /// Watch and backload nested subfolders, not just the top level of `target_dir`.
+    #[arg(long)]
+    recursive: bool,
+
+    /// Glob-based destination rules, loaded from `sorter_rules.toml` in `target_dir`.
+    #[arg(skip)]
+    rules: RuleSet,
+
+    /// Paths excluded from sorting, loaded from `.sorterignore` in `target_dir`.
+    #[arg(skip)]
+    ignores: IgnoreSet,
+
+    /// How long, in milliseconds, a file must sit unchanged before it's sorted.
+    #[arg(long, default_value_t = 2000)]
+    settle_ms: u64,
+
+    /// Paths seen since their last settling check, alongside when they were
+    /// last seen and the size they had at that point.
+    #[arg(skip)]
+    pending: RefCell<HashMap<PathBuf, (Instant, u64)>>,
+
+    /// How to handle a destination file name that's already taken.
+    #[arg(long, value_enum, default_value_t = OnConflict::Rename)]
+    on_conflict: OnConflict,
+}
+
+/// A destination path claimed by `reserve_destination`, distinguishing a
+/// freshly created empty placeholder from an existing file being
+/// overwritten in place, so callers know which one to clean up on failure.
+enum ReservedDestination {
+    /// Created via `create_new`; must be removed if the move fails, or it's
+    /// left behind as an orphaned empty file that would collide with the
+    /// next attempt to sort into the same destination.
+    Placeholder(PathBuf),
+    /// The existing file being overwritten; never removed on failure.
+    Existing(PathBuf),
+}
+
+impl ReservedDestination {
+    fn path(&self) -> &Path {
+        match self {
+            ReservedDestination::Placeholder(p) | ReservedDestination::Existing(p) => p,
+        }
+    }
+}
+
+/// What to do when the computed destination for a file already exists.
+#[derive(Clone, Copy, ValueEnum)]
+enum OnConflict {
+    /// Append a counter to the file name, e.g. `report (1).pdf`.
+    Rename,
+    /// Leave the file where it is and log a warning.
+    Skip,
+    /// Move onto the existing file, replacing it.
+    Overwrite,
 }
 
 impl Display for FileWatcher {
@@ -27,13 +118,37 @@ impl FileWatcher {
             std::process::exit(1);
         }
 
+        // Load any glob-based destination rules and ignore patterns before we start sorting
+        self.rules = RuleSet::load(&self.target_dir)?;
+        self.ignores = IgnoreSet::load(&self.target_dir)?;
+
         // Read target directory and backload any missed target files
-        let paths = read_dir(&self.target_dir)?;
-        for path in paths {
-            let path = path?.path();
-            if path.is_file() {
-                if let Err(e) = self.handle_file(&path) {
-                    log::error!("Error handling file {path:?}: {e:?}. Skipping file ...");
+        if self.recursive {
+            for entry in WalkDir::new(&self.target_dir)
+                .into_iter()
+                .filter_entry(|e| {
+                    !(self.ignores.is_ignored(e.path(), e.file_type().is_dir())
+                        || e.file_type().is_dir() && self.is_sorted_dir(e.path()))
+                })
+            {
+                let path = entry?.path().to_path_buf();
+                if path.is_file() {
+                    if let Err(e) = self.handle_file(&path) {
+                        log::error!("Error handling file {path:?}: {e:?}. Skipping file ...");
+                    }
+                }
+            }
+        } else {
+            let paths = read_dir(&self.target_dir)?;
+            for path in paths {
+                let path = path?.path();
+                if self.ignores.is_ignored_path(&path) {
+                    continue;
+                }
+                if path.is_file() {
+                    if let Err(e) = self.handle_file(&path) {
+                        log::error!("Error handling file {path:?}: {e:?}. Skipping file ...");
+                    }
                 }
             }
         }
@@ -45,48 +160,131 @@ impl FileWatcher {
         let watch_dir = Path::new(&self.target_dir);
         let (tx, rx) = std::sync::mpsc::channel();
         let mut watcher = RecommendedWatcher::new(tx, Config::default())?;
-        watcher.watch(watch_dir.as_ref(), RecursiveMode::NonRecursive)?;
+        let recursive_mode = match self.recursive {
+            true => RecursiveMode::Recursive,
+            false => RecursiveMode::NonRecursive,
+        };
+        watcher.watch(watch_dir.as_ref(), recursive_mode)?;
         log::info!("Filewatcher successfully initialised!");
 
-        // Handle each event
-        for res in rx {
-            match res {
-                Ok(event) => {
+        // Handle each event, then give pending paths a chance to settle
+        let settle_duration = Duration::from_millis(self.settle_ms);
+        loop {
+            match rx.recv_timeout(settle_duration) {
+                Ok(Ok(event)) => {
                     if let Err(e) = self.handle_event(event) {
                         log::error!("Error handling event: {e:?}. Skipping event ...")
                     }
                 }
-                Err(e) => log::error!("Unexpected error receiving event from channel: {:?}", e),
+                Ok(Err(e)) => {
+                    log::error!("Unexpected error receiving event from channel: {:?}", e)
+                }
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => break,
             }
+            self.flush_settled(settle_duration);
         }
         Ok(())
     }
 
+    /// Buffers `Create`/`Modify` paths instead of sorting them immediately,
+    /// so a file that's still being written (or mid-download-then-rename)
+    /// isn't grabbed before it's finished. `flush_settled` sorts a path once
+    /// it has gone quiet.
     fn handle_event(&self, event: Event) -> Result<()> {
         match event.kind {
-            EventKind::Create(CreateKind::File) => {
+            EventKind::Create(CreateKind::File) | EventKind::Modify(_) => {
+                let mut pending = self.pending.borrow_mut();
                 for src in event.paths.iter() {
-                    log::info!("Handling file {src:?} ... ");
-                    match self.handle_file(&src) {
-                        Ok(_) => log::info!("File moved successfully!"),
-                        Err(e) => log::error!("Error handling file {src:?}: {e:?}. Skipping ..."),
+                    if is_sorter_tmp_file(src) {
+                        log::info!("{src:?} is a sorter temp file. Skipping ...");
+                        continue;
+                    }
+                    if self.is_already_sorted(src) {
+                        log::info!("{src:?} is already sorted. Skipping ...");
+                        continue;
                     }
+                    if self.ignores.is_ignored(src, src.is_dir()) {
+                        log::info!("{src:?} matches an ignore pattern. Skipping ...");
+                        continue;
+                    }
+                    let size = std::fs::metadata(src).map(|m| m.len()).unwrap_or(0);
+                    log::info!("Tracking {src:?}, waiting for it to settle ...");
+                    pending.insert(src.clone(), (Instant::now(), size));
                 }
             }
-            _ => log::info!("Event {:?} encountered. Skipping ...", event.kind)
+            _ => log::info!("Event {:?} encountered. Skipping ...", event.kind),
         }
         Ok(())
     }
 
+    /// Sorts any tracked path that has gone quiet for `settle_duration` with
+    /// a stable size across two successive stat calls.
+    fn flush_settled(&self, settle_duration: Duration) {
+        let now = Instant::now();
+        let mut settled = Vec::new();
+        {
+            let mut pending = self.pending.borrow_mut();
+            for (path, (last_seen, last_size)) in pending.iter_mut() {
+                if now.duration_since(*last_seen) < settle_duration {
+                    continue;
+                }
+                match std::fs::metadata(path) {
+                    Ok(meta) if meta.len() == *last_size => settled.push(path.clone()),
+                    Ok(meta) => {
+                        *last_size = meta.len();
+                        *last_seen = now;
+                    }
+                    Err(_) => settled.push(path.clone()),
+                }
+            }
+            for path in &settled {
+                pending.remove(path);
+            }
+        }
+
+        for path in settled {
+            if !path.is_file() {
+                continue;
+            }
+            log::info!("Handling file {path:?} ... ");
+            match self.handle_file(&path) {
+                Ok(_) => log::info!("File moved successfully!"),
+                Err(e) => log::error!("Error handling file {path:?}: {e:?}. Skipping ..."),
+            }
+        }
+    }
+
     fn handle_file(&self, handle: &PathBuf) -> Result<()> {
         let file_name = handle.file_name();
-        let ext = handle.extension();
-        match (file_name, ext) {
-            (Some(file_name), Some(ext)) => {
-                let dir = Path::new(&self.target_dir).join(ext);
+        let destination_name = self.resolve_destination_name(handle);
+        match (file_name, destination_name) {
+            (Some(file_name), Some(destination_name)) => {
+                if !handle.is_file() {
+                    log::warn!("{handle:?} has not been determined to be a file. Skipping ...");
+                    return Ok(());
+                }
+
+                let dir = Path::new(&self.target_dir).join(destination_name);
                 self.create_dir_if_not_exists(&dir)?;
                 let destination = dir.join(file_name);
-                self.move_file(handle, destination)?;
+                match self.reserve_destination(&destination)? {
+                    Some(reserved) => {
+                        if let Err(e) = self.move_file(handle, reserved.path()) {
+                            if let ReservedDestination::Placeholder(placeholder) = &reserved {
+                                if let Err(cleanup_err) = remove_file(placeholder) {
+                                    log::error!(
+                                        "Failed to remove orphaned placeholder {placeholder:?}: {cleanup_err:?}"
+                                    );
+                                }
+                            }
+                            return Err(e);
+                        }
+                    }
+                    None => {
+                        log::warn!("{destination:?} already exists. Skipping {handle:?} ...")
+                    }
+                }
                 Ok(())
             }
             _ => Err(anyhow!(
@@ -96,6 +294,95 @@ impl FileWatcher {
         }
     }
 
+    /// Resolves a naming collision at `destination` according to
+    /// `self.on_conflict`, returning the path to actually move into, or
+    /// `None` when the file should be skipped. A freshly reserved path is
+    /// claimed up front with `create_new` so the slot can't be taken between
+    /// this check and the move; the caller is responsible for removing that
+    /// placeholder if the move itself fails.
+    fn reserve_destination(&self, destination: &Path) -> Result<Option<ReservedDestination>> {
+        if let Some(reserved) = self.try_reserve(destination)? {
+            return Ok(Some(ReservedDestination::Placeholder(reserved)));
+        }
+
+        match self.on_conflict {
+            OnConflict::Overwrite => Ok(Some(ReservedDestination::Existing(
+                destination.to_path_buf(),
+            ))),
+            OnConflict::Skip => Ok(None),
+            OnConflict::Rename => {
+                let mut n: u32 = 1;
+                loop {
+                    let candidate = numbered_path(destination, n);
+                    if let Some(reserved) = self.try_reserve(&candidate)? {
+                        return Ok(Some(ReservedDestination::Placeholder(reserved)));
+                    }
+                    n += 1;
+                }
+            }
+        }
+    }
+
+    /// Attempts to atomically claim `path` by creating it, returning `None`
+    /// if it's already taken.
+    fn try_reserve(&self, path: &Path) -> Result<Option<PathBuf>> {
+        match OpenOptions::new().write(true).create_new(true).open(path) {
+            Ok(_) => Ok(Some(path.to_path_buf())),
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Computes the destination subfolder name for `handle`: the first
+    /// matching rule in `self.rules`, falling back to the file's extension
+    /// when no rule matches.
+    fn resolve_destination_name(&self, handle: &Path) -> Option<String> {
+        if let Some(file_name) = handle.file_name().and_then(|f| f.to_str()) {
+            if let Some(destination) = self.rules.resolve(file_name) {
+                return Some(destination.to_string());
+            }
+        }
+        handle
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(String::from)
+    }
+
+    /// A file counts as already sorted if it already sits directly inside
+    /// the destination `handle_file` would have moved it to - its own
+    /// extension folder, or a rule's destination folder when a rule routes
+    /// it elsewhere. In recursive mode this stops the watcher from reacting
+    /// to the create events it generates by moving files into place, and
+    /// from re-`rename`ing a rule-routed file onto itself.
+    fn is_already_sorted(&self, path: &Path) -> bool {
+        match (
+            path.parent().and_then(Path::file_name).and_then(|n| n.to_str()),
+            self.resolve_destination_name(path),
+        ) {
+            (Some(parent_name), Some(destination_name)) => parent_name == destination_name,
+            _ => false,
+        }
+    }
+
+    /// A directory counts as a sorted destination folder if it's a direct
+    /// child of `target_dir` and already holds at least one file that
+    /// `is_already_sorted` recognizes as belonging there. A plain subfolder
+    /// the user dropped into `target_dir` (e.g. `docs/`) never matches this,
+    /// so a recursive backload still walks into it; only folders actually
+    /// populated by `handle_file` get pruned.
+    fn is_sorted_dir(&self, dir: &Path) -> bool {
+        if dir.parent() != Some(Path::new(&self.target_dir)) {
+            return false;
+        }
+        read_dir(dir)
+            .map(|entries| {
+                entries
+                    .filter_map(Result::ok)
+                    .any(|entry| self.is_already_sorted(&entry.path()))
+            })
+            .unwrap_or(false)
+    }
+
     fn create_dir_if_not_exists(&self, dir: &PathBuf) -> Result<()> {
         match dir.exists() {
             true => Ok(()),
@@ -112,14 +399,61 @@ impl FileWatcher {
         dest: D,
     ) -> Result<()> {
         let src = src.as_ref();
+        let dest = dest.as_ref();
         log::info!("Attempting to move file from {src:?} to {dest:?}");
         match src.is_file() {
-            true => rename(src, dest)?,
+            true => {
+                if let Err(e) = rename(src, dest) {
+                    if e.raw_os_error() == Some(EXDEV) {
+                        log::warn!(
+                            "{src:?} and {dest:?} are on different devices, falling back to copy ..."
+                        );
+                        self.copy_then_remove(src, dest)?;
+                    } else {
+                        return Err(e.into());
+                    }
+                }
+            }
             false => log::warn!("{src:?} has not been determined to be a file. Skipping ..."),
         }
         Ok(())
     }
 
+    /// Fallback for cross-device moves: copy `src` to a temp file next to
+    /// `dest`, rename it into place atomically, then remove `src`. Used when
+    /// `rename` fails with `EXDEV` because the destination lives on a
+    /// different filesystem/mount than the source.
+    fn copy_then_remove(&self, src: &Path, dest: &Path) -> Result<()> {
+        let file_name = dest
+            .file_name()
+            .ok_or_else(|| anyhow!("Destination {:?} has no file name", dest))?;
+        let parent = dest
+            .parent()
+            .ok_or_else(|| anyhow!("Destination {:?} has no parent directory", dest))?;
+        let tmp_dest = parent.join(format!(".{}.sorter-tmp", file_name.to_string_lossy()));
+
+        let src_meta = src.metadata()?;
+        copy(src, &tmp_dest)?;
+
+        let copied_len = tmp_dest.metadata()?.len();
+        if copied_len != src_meta.len() {
+            remove_file(&tmp_dest)?;
+            return Err(anyhow!(
+                "Copied file size {copied_len} does not match source size {} for {src:?}",
+                src_meta.len()
+            ));
+        }
+
+        let tmp_file = File::open(&tmp_dest)?;
+        tmp_file.set_modified(src_meta.modified()?)?;
+        tmp_file.set_permissions(src_meta.permissions())?;
+        drop(tmp_file);
+
+        rename(&tmp_dest, dest)?;
+        remove_file(src)?;
+        Ok(())
+    }
+
     fn expand_path(&mut self, target_directory: &str) -> Result<()> {
         let target_dir_path = PathBuf::from_str(target_directory)?;
 
@@ -140,7 +474,7 @@ impl FileWatcher {
 
 #[cfg(test)]
 mod test {
-    use super::FileWatcher;
+    use super::{FileWatcher, OnConflict, ReservedDestination};
     use anyhow::Result;
     use notify::{
         event::{CreateKind, EventKind},
@@ -151,6 +485,12 @@ mod test {
     fn create_fw_instance(target_dir: &str) -> FileWatcher {
         FileWatcher {
             target_dir: target_dir.into(),
+            recursive: false,
+            rules: Default::default(),
+            ignores: Default::default(),
+            settle_ms: 2000,
+            pending: Default::default(),
+            on_conflict: OnConflict::Rename,
         }
     }
 
@@ -169,11 +509,11 @@ mod test {
         let fw = create_fw_instance("bar");
 
         // Assert source file does not exist in target directory
-        assert_eq!(new_path.exists(), false);
+        assert!(!new_path.exists());
 
         // Move file to target directory and assert it path exists after move
-        let _ = fw.move_file(&target_dir, &new_path)?;
-        assert_eq!(new_path.exists(), true);
+        fw.move_file(&target_dir, &new_path)?;
+        assert!(new_path.exists());
         Ok(())
     }
 
@@ -188,11 +528,11 @@ mod test {
 
         // Assert txt directory does not exist as a subdirectory of the filewatcher target
         let non_existent_dir = dir.join("txt");
-        assert_eq!(non_existent_dir.exists(), false);
+        assert!(!non_existent_dir.exists());
 
         // Run function and assert that the directory has been created
-        let _ = fw.create_dir_if_not_exists(&non_existent_dir);
-        assert_eq!(non_existent_dir.exists(), true);
+        fw.create_dir_if_not_exists(&non_existent_dir)?;
+        assert!(non_existent_dir.exists());
 
         Ok(())
     }
@@ -220,12 +560,214 @@ mod test {
 
         let fw = create_fw_instance(&src_dir.display().to_string());
 
-        assert_eq!(src_path.exists(), true);
-        assert_eq!(dest_path.exists(), false);
+        assert!(src_path.exists());
+        assert!(!dest_path.exists());
         let result = fw.handle_file(&src_path);
         assert!(result.is_ok());
-        assert_eq!(src_path.exists(), false);
-        assert_eq!(dest_path.exists(), true);
+        assert!(!src_path.exists());
+        assert!(dest_path.exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_copy_then_remove_preserves_size_and_removes_source() -> Result<()> {
+        let src_dir = tempfile::tempdir()?;
+        let src_path = src_dir.path().join("some_file.txt");
+        std::fs::write(&src_path, b"hello world")?;
+
+        let dest_dir = tempfile::tempdir()?;
+        let dest_path = dest_dir.path().join("some_file.txt");
+
+        let fw = create_fw_instance("bar");
+        fw.copy_then_remove(&src_path, &dest_path)?;
+
+        assert!(!src_path.exists());
+        assert!(dest_path.exists());
+        assert_eq!(std::fs::read(&dest_path)?, b"hello world");
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_already_sorted() {
+        let fw = create_fw_instance("foo");
+        assert!(fw.is_already_sorted(&PathBuf::from("foo/pdf/report.pdf")));
+        assert!(!fw.is_already_sorted(&PathBuf::from("foo/downloads/report.pdf")));
+    }
+
+    #[test]
+    fn test_is_already_sorted_recognizes_rule_destination() -> Result<()> {
+        let src_dir = tempfile::tempdir()?;
+        std::fs::write(
+            src_dir.path().join("sorter_rules.toml"),
+            "[[rule]]\npattern = \"invoice_*.pdf\"\ndestination = \"finance\"\n",
+        )?;
+
+        let mut fw = create_fw_instance(&src_dir.path().display().to_string());
+        fw.rules = super::rules::RuleSet::load(&fw.target_dir)?;
+
+        assert!(fw.is_already_sorted(&src_dir.path().join("finance").join("invoice_march.pdf")));
+        assert!(!fw.is_already_sorted(&src_dir.path().join("downloads").join("invoice_march.pdf")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_sorted_dir_requires_a_matching_sorted_file() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let target = temp_dir.path();
+        let fw = create_fw_instance(&target.display().to_string());
+
+        // A real extension-destination folder holds a file already sorted into it.
+        let pdf_dir = target.join("pdf");
+        std::fs::create_dir(&pdf_dir)?;
+        std::fs::write(pdf_dir.join("report.pdf"), b"data")?;
+        assert!(fw.is_sorted_dir(&pdf_dir));
+
+        // A plain subfolder the user created is not a sorted destination, even
+        // though it's a direct child of target_dir, so backload still walks it.
+        let docs_dir = target.join("docs");
+        std::fs::create_dir(&docs_dir)?;
+        std::fs::write(docs_dir.join("report.pdf"), b"data")?;
+        assert!(!fw.is_sorted_dir(&docs_dir));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_backload_recursive_visits_nested_subfolders() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let target = temp_dir.path();
+        let nested_dir = target.join("docs");
+        std::fs::create_dir(&nested_dir)?;
+        let nested_file = nested_dir.join("report.pdf");
+        std::fs::write(&nested_file, b"data")?;
+
+        let mut fw = create_fw_instance(&target.display().to_string());
+        fw.recursive = true;
+        fw.backload()?;
+
+        assert!(!nested_file.exists());
+        assert!(target.join("pdf").join("report.pdf").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_handle_file_routes_via_rule_before_extension() -> Result<()> {
+        let src_dir = tempfile::tempdir()?;
+        std::fs::write(
+            src_dir.path().join("sorter_rules.toml"),
+            "[[rule]]\npattern = \"invoice_*.pdf\"\ndestination = \"finance\"\n",
+        )?;
+
+        let src_path = src_dir.path().join("invoice_march.pdf");
+        let _ = File::create(&src_path)?;
+
+        let mut fw = create_fw_instance(&src_dir.path().display().to_string());
+        fw.rules = super::rules::RuleSet::load(&fw.target_dir)?;
+
+        let result = fw.handle_file(&src_path);
+        assert!(result.is_ok());
+        assert!(src_dir.path().join("finance").join("invoice_march.pdf").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_backload_skips_ignored_files() -> Result<()> {
+        let src_dir = tempfile::tempdir()?;
+        std::fs::write(src_dir.path().join(".sorterignore"), "*.txt\n")?;
+
+        let ignored_path = src_dir.path().join("README.txt");
+        let _ = File::create(&ignored_path)?;
+
+        let mut fw = create_fw_instance(&src_dir.path().display().to_string());
+        fw.backload()?;
+
+        assert!(ignored_path.exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_handle_event_tracks_path_without_moving_immediately() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let src_path = temp_dir.path().join("some_file.txt");
+        let _ = File::create(&src_path)?;
+
+        let fw = create_fw_instance(&temp_dir.path().display().to_string());
+        let event = Event {
+            kind: EventKind::Create(CreateKind::File),
+            paths: vec![src_path.clone()],
+            ..Default::default()
+        };
+
+        fw.handle_event(event)?;
+        assert!(src_path.exists());
+        assert!(fw.pending.borrow().contains_key(&src_path));
+        Ok(())
+    }
+
+    #[test]
+    fn test_flush_settled_moves_file_once_quiet() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let src_path = temp_dir.path().join("some_file.txt");
+        let _ = File::create(&src_path)?;
+
+        let fw = create_fw_instance(&temp_dir.path().display().to_string());
+        let event = Event {
+            kind: EventKind::Create(CreateKind::File),
+            paths: vec![src_path.clone()],
+            ..Default::default()
+        };
+        fw.handle_event(event)?;
+
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        fw.flush_settled(std::time::Duration::from_millis(5));
+
+        assert!(!src_path.exists());
+        assert!(temp_dir.path().join("txt").join("some_file.txt").exists());
+        assert!(fw.pending.borrow().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_handle_file_renames_on_collision_by_default() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let src_dir = temp_dir.path();
+
+        let first_src = src_dir.join("report.pdf");
+        std::fs::write(&first_src, b"first")?;
+        let second_src = src_dir.join("report2.pdf");
+        std::fs::write(&second_src, b"second")?;
+
+        let fw = create_fw_instance(&src_dir.display().to_string());
+        fw.handle_file(&first_src)?;
+
+        // Rename the second source onto the same file name as the first, then sort it
+        let colliding_src = src_dir.join("report.pdf");
+        std::fs::rename(&second_src, &colliding_src)?;
+        fw.handle_file(&colliding_src)?;
+
+        assert!(src_dir.join("pdf").join("report.pdf").exists());
+        assert!(src_dir.join("pdf").join("report (1).pdf").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_handle_file_skips_on_collision_when_configured() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let src_dir = temp_dir.path();
+
+        let dest_dir = src_dir.join("pdf");
+        std::fs::create_dir(&dest_dir)?;
+        std::fs::write(dest_dir.join("report.pdf"), b"existing")?;
+
+        let src_path = src_dir.join("report.pdf");
+        std::fs::write(&src_path, b"incoming")?;
+
+        let mut fw = create_fw_instance(&src_dir.display().to_string());
+        fw.on_conflict = OnConflict::Skip;
+        fw.handle_file(&src_path)?;
+
+        assert!(src_path.exists());
+        assert_eq!(std::fs::read(dest_dir.join("report.pdf"))?, b"existing");
         Ok(())
     }
 
@@ -236,4 +778,74 @@ mod test {
         let result = fw.handle_file(&stupid_file);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_handle_file_skips_non_file_without_reserving() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let src_dir = temp_dir.path();
+        let missing_path = src_dir.join("ghost.pdf");
+
+        let fw = create_fw_instance(&src_dir.display().to_string());
+        let result = fw.handle_file(&missing_path);
+
+        assert!(result.is_ok());
+        assert!(!src_dir.join("pdf").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_reserve_destination_distinguishes_placeholder_from_existing() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let dir = temp_dir.path();
+
+        // No conflict: reserve_destination claims a fresh, empty placeholder
+        // that a failed move must clean up.
+        let fw = create_fw_instance(&dir.display().to_string());
+        let fresh = dir.join("report.pdf");
+        let reserved = fw.reserve_destination(&fresh)?.expect("slot is free");
+        assert!(matches!(reserved, ReservedDestination::Placeholder(_)));
+        assert!(reserved.path().exists());
+
+        // Conflict with Overwrite: the existing file is returned as-is,
+        // never created by `reserve_destination`, so a failed move must
+        // leave it untouched rather than deleting it.
+        let mut fw = fw;
+        fw.on_conflict = OnConflict::Overwrite;
+        let occupied = dir.join("existing.pdf");
+        std::fs::write(&occupied, b"keep me")?;
+        let reserved = fw.reserve_destination(&occupied)?.expect("overwrite reuses the slot");
+        assert!(matches!(reserved, ReservedDestination::Existing(_)));
+        assert_eq!(std::fs::read(reserved.path())?, b"keep me");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_placeholder_is_removable_after_a_failed_move() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let src_dir = temp_dir.path();
+        let src_path = src_dir.join("report.pdf");
+        std::fs::write(&src_path, b"data")?;
+
+        let dest_dir = src_dir.join("pdf");
+        std::fs::create_dir(&dest_dir)?;
+        let destination = dest_dir.join("report.pdf");
+
+        let fw = create_fw_instance(&src_dir.display().to_string());
+        let reserved = fw
+            .reserve_destination(&destination)?
+            .expect("slot is free");
+        assert!(reserved.path().exists());
+
+        // A directory can never be `rename`d over, so this move fails after
+        // the placeholder has already been claimed; the cleanup path below
+        // mirrors what `handle_file` does on that same `Err`.
+        let result = fw.move_file(&src_path, dest_dir.as_path());
+        assert!(result.is_err());
+        if let ReservedDestination::Placeholder(placeholder) = &reserved {
+            std::fs::remove_file(placeholder)?;
+        }
+        assert!(!reserved.path().exists());
+        Ok(())
+    }
 }
@@ -0,0 +1,148 @@
+use anyhow::Result;
+use std::fs::read_to_string;
+use std::path::Path;
+
+use super::rules::matches_glob;
+
+/// Name of the optional gitignore-style ignore file, read from the root of
+/// `target_dir`.
+const IGNORE_FILE_NAME: &str = ".sorterignore";
+
+struct IgnorePattern {
+    pattern: String,
+    negate: bool,
+    dir_only: bool,
+    /// A pattern with no wildcard characters names an exact path. When a
+    /// broader glob pattern already ignores that same path, the literal
+    /// entry re-includes it instead; otherwise it's a plain ignore entry
+    /// like any other.
+    literal: bool,
+}
+
+/// Gitignore-style patterns excluding paths in `target_dir` from sorting.
+#[derive(Default)]
+pub struct IgnoreSet {
+    patterns: Vec<IgnorePattern>,
+}
+
+impl IgnoreSet {
+    /// Loads `.sorterignore` from `target_dir` if it exists, returning an
+    /// empty `IgnoreSet` when there is no ignore file to load.
+    pub fn load(target_dir: &str) -> Result<Self> {
+        let path = Path::new(target_dir).join(IGNORE_FILE_NAME);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = read_to_string(&path)?;
+        Ok(Self {
+            patterns: contents.lines().filter_map(parse_line).collect(),
+        })
+    }
+
+    /// Returns whether `path` should be skipped from sorting. Glob patterns
+    /// are evaluated top-to-bottom with last-match-wins (`!` re-includes). A
+    /// matching literal (non-glob) pattern only overrides that verdict when a
+    /// broader glob already ignored the same path; on its own, with no
+    /// competing glob, it behaves like any other ignore pattern — listing
+    /// `README.txt` by itself leaves it in place rather than forcing it to be
+    /// processed.
+    pub fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            return false;
+        };
+
+        let mut ignored = false;
+        for pattern in self.patterns.iter().filter(|p| !p.literal) {
+            if pattern.dir_only && !is_dir {
+                continue;
+            }
+            if matches_glob(&pattern.pattern, name) {
+                ignored = !pattern.negate;
+            }
+        }
+
+        let literal_match = self
+            .patterns
+            .iter()
+            .filter(|p| p.literal && (!p.dir_only || is_dir))
+            .rfind(|p| p.pattern == name);
+        if let Some(literal) = literal_match {
+            return !literal.negate && !ignored;
+        }
+
+        ignored
+    }
+
+    /// Convenience wrapper for callers that only have a path.
+    pub fn is_ignored_path(&self, path: &Path) -> bool {
+        self.is_ignored(path, path.is_dir())
+    }
+}
+
+fn parse_line(line: &str) -> Option<IgnorePattern> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+
+    let negate = line.starts_with('!');
+    let line = line.strip_prefix('!').unwrap_or(line);
+    let dir_only = line.ends_with('/');
+    let pattern = line.trim_end_matches('/').to_string();
+    let literal = !pattern.contains('*') && !pattern.contains('?');
+
+    Some(IgnorePattern {
+        pattern,
+        negate,
+        dir_only,
+        literal,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::IgnoreSet;
+    use std::path::Path;
+
+    fn ignore_set(contents: &str) -> IgnoreSet {
+        IgnoreSet {
+            patterns: contents.lines().filter_map(super::parse_line).collect(),
+        }
+    }
+
+    #[test]
+    fn test_blank_lines_and_comments_skipped() {
+        let set = ignore_set("\n# a comment\n*.tmp\n");
+        assert!(set.is_ignored(Path::new("foo.tmp"), false));
+        assert!(!set.is_ignored(Path::new("foo.txt"), false));
+    }
+
+    #[test]
+    fn test_trailing_slash_matches_directories_only() {
+        let set = ignore_set("builds/\n");
+        assert!(set.is_ignored(Path::new("builds"), true));
+        assert!(!set.is_ignored(Path::new("builds"), false));
+    }
+
+    #[test]
+    fn test_negation_reincludes_previously_excluded_pattern() {
+        let set = ignore_set("*.log\n!keep.log\n");
+        assert!(set.is_ignored(Path::new("debug.log"), false));
+        assert!(!set.is_ignored(Path::new("keep.log"), false));
+    }
+
+    #[test]
+    fn test_explicit_literal_include_overrides_broader_glob() {
+        let set = ignore_set("*.txt\nREADME.txt\n");
+        assert!(set.is_ignored(Path::new("notes.txt"), false));
+        assert!(!set.is_ignored(Path::new("README.txt"), false));
+    }
+
+    #[test]
+    fn test_literal_pattern_without_competing_glob_still_ignores() {
+        let set = ignore_set("README.txt\n");
+        assert!(set.is_ignored(Path::new("README.txt"), false));
+        assert!(!set.is_ignored(Path::new("notes.txt"), false));
+    }
+}
@@ -0,0 +1,96 @@
+use anyhow::Result;
+use serde::Deserialize;
+use std::fs::read_to_string;
+use std::path::Path;
+
+/// Name of the optional rule config file, read from the root of `target_dir`.
+const RULES_FILE_NAME: &str = "sorter_rules.toml";
+
+#[derive(Deserialize)]
+struct RuleConfig {
+    #[serde(default)]
+    rule: Vec<Rule>,
+}
+
+#[derive(Deserialize)]
+struct Rule {
+    pattern: String,
+    destination: String,
+}
+
+/// Glob-pattern rules mapping a file name to a destination subfolder,
+/// checked before falling back to extension-based sorting.
+#[derive(Default)]
+pub struct RuleSet {
+    rules: Vec<Rule>,
+}
+
+impl RuleSet {
+    /// Loads `sorter_rules.toml` from `target_dir` if it exists, returning an
+    /// empty `RuleSet` when there is no config file to load.
+    pub fn load(target_dir: &str) -> Result<Self> {
+        let path = Path::new(target_dir).join(RULES_FILE_NAME);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = read_to_string(&path)?;
+        let config: RuleConfig = toml::from_str(&contents)?;
+        Ok(Self {
+            rules: config.rule,
+        })
+    }
+
+    /// Returns the destination subfolder for `file_name`, trying each rule
+    /// top-to-bottom and returning the first match.
+    pub fn resolve(&self, file_name: &str) -> Option<&str> {
+        self.rules
+            .iter()
+            .find(|rule| matches_glob(&rule.pattern, file_name))
+            .map(|rule| rule.destination.as_str())
+    }
+}
+
+/// Matches `name` against a simple wildcard `pattern`, where `?` matches
+/// exactly one character and `*` matches any run of characters (including
+/// none).
+pub(super) fn matches_glob(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    matches_glob_from(&pattern, &name)
+}
+
+fn matches_glob_from(pattern: &[char], name: &[char]) -> bool {
+    match pattern.first() {
+        None => name.is_empty(),
+        Some('*') => {
+            matches_glob_from(&pattern[1..], name)
+                || (!name.is_empty() && matches_glob_from(pattern, &name[1..]))
+        }
+        Some('?') => !name.is_empty() && matches_glob_from(&pattern[1..], &name[1..]),
+        Some(c) => name.first() == Some(c) && matches_glob_from(&pattern[1..], &name[1..]),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::matches_glob;
+
+    #[test]
+    fn test_matches_glob_star() {
+        assert!(matches_glob("*.jpg", "holiday.jpg"));
+        assert!(!matches_glob("*.jpg", "holiday.png"));
+    }
+
+    #[test]
+    fn test_matches_glob_question_mark() {
+        assert!(matches_glob("img?.png", "img1.png"));
+        assert!(!matches_glob("img?.png", "img12.png"));
+    }
+
+    #[test]
+    fn test_matches_glob_prefix_and_suffix() {
+        assert!(matches_glob("invoice_*.pdf", "invoice_march.pdf"));
+        assert!(!matches_glob("invoice_*.pdf", "receipt_march.pdf"));
+    }
+}